@@ -7,7 +7,7 @@
 //! You can find the API
 //! [here](https://developer.arm.com/architectures/security-architectures/platform-security-architecture/documentation).
 
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(
     nonstandard_style,
     const_err,
@@ -39,6 +39,12 @@
 // This one is hard to avoid.
 #![allow(clippy::multiple_crate_versions)]
 
+// The default build is `no_std`, so `std` is not in the extern prelude; test-only scaffolding
+// below needs it for `std::sync::Mutex`, which is why it is pulled in here explicitly rather
+// than relying on the `std` feature (tests must also pass on a plain, feature-free build).
+#[cfg(test)]
+extern crate std;
+
 pub mod operations;
 pub mod types;
 
@@ -46,6 +52,7 @@ use core::sync::atomic::{AtomicBool, Ordering};
 use types::status::{Error, Result, Status};
 
 static INITIALISED: AtomicBool = AtomicBool::new(false);
+static STRICT_INIT: AtomicBool = AtomicBool::new(true);
 
 /// Initialize the PSA Crypto library
 ///
@@ -53,18 +60,138 @@ static INITIALISED: AtomicBool = AtomicBool::new(false);
 /// Applications are permitted to call this function more than once. Once a call succeeds,
 /// subsequent calls are guaranteed to succeed.
 pub fn init() -> Result<()> {
-    // It it not a problem to call psa_crypto_init more than once.
+    // Another thread may already have completed initialisation; in that case there is nothing
+    // left to do, and re-running psa_crypto_init would be wasted work.
+    if INITIALISED.load(Ordering::Acquire) {
+        return Ok(());
+    }
+
+    // It is not a problem to call psa_crypto_init more than once, so if several threads race to
+    // get here it is harmless for more than one of them to succeed; whichever one wins the
+    // compare-exchange below is the one that "commits" the initialised state.
     Status::from(unsafe { psa_crypto_sys::psa_crypto_init() }).to_result()?;
-    let _ = INITIALISED.compare_and_swap(false, true, Ordering::Relaxed);
+    let _ = INITIALISED.compare_exchange(false, true, Ordering::Release, Ordering::Relaxed);
 
     Ok(())
 }
 
 /// Check if the PSA Crypto library has been initialized
 pub fn initialized() -> Result<()> {
-    if INITIALISED.load(Ordering::Relaxed) {
+    if INITIALISED.load(Ordering::Acquire) {
         Ok(())
     } else {
         Err(Error::BadState)
     }
 }
+
+/// Configure whether the `operations` module enforces the initialized precondition
+///
+/// When enabled (the default), every public function in [`operations`] returns
+/// [`Error::BadState`] if called before [`init`] has succeeded, as the PSA spec mandates that
+/// behaviour is undefined - and may have security implications, such as an unseeded RNG - if an
+/// operation runs before initialization. Performance-sensitive deployments that can otherwise
+/// guarantee their own initialization ordering may disable the check to skip the per-call atomic
+/// load.
+pub fn set_strict_init(strict: bool) {
+    STRICT_INIT.store(strict, Ordering::Release);
+}
+
+/// Ensure the PSA Crypto library has been initialized before an operation proceeds
+///
+/// Called by every public function in the `operations` module. Returns [`Error::BadState`] when
+/// strict initialization checking is enabled (see [`set_strict_init`]) and the library has not
+/// been initialized.
+pub(crate) fn ensure_initialized() -> Result<()> {
+    if STRICT_INIT.load(Ordering::Acquire) {
+        initialized()
+    } else {
+        Ok(())
+    }
+}
+
+/// Deinitialize the PSA Crypto library
+///
+/// This releases the resources held by the underlying implementation and clears the
+/// initialized state, so that a subsequent call to [`init`] performs a full re-initialization.
+/// After `deinit` and before the next successful `init`, [`initialized`] returns
+/// [`Error::BadState`], and so do the functions of the [`operations`] module while strict
+/// initialization checking is enabled (see [`set_strict_init`]).
+///
+/// # Single owner only
+///
+/// This is an unconditional, process-wide teardown: it does not reference-count outstanding
+/// callers. If any other code in the process is still relying on the library being initialized
+/// - including another live [`CryptoGuard`] - calling `deinit` pulls the library out from under
+/// it, and that code's next operation will fail with [`Error::BadState`] (or, with strict
+/// checking disabled, reach the deinitialized implementation). Only call this
+/// (directly, or by dropping a [`CryptoGuard`]) when you are certain you are the only user of the
+/// library in the process; do not use it, or `init_scoped`, from code that shares the process
+/// with other PSA Crypto users.
+///
+/// # Backend assumption
+///
+/// This calls the mbedtls-specific `mbedtls_psa_crypto_free`, which is not part of the portable
+/// PSA Crypto API. Unlike [`init`], which uses the spec-defined `psa_crypto_init`, `deinit` ties
+/// this otherwise backend-generic wrapper to an mbedtls-based implementation.
+pub fn deinit() {
+    unsafe { psa_crypto_sys::mbedtls_psa_crypto_free() };
+    INITIALISED.store(false, Ordering::Release);
+}
+
+/// RAII guard that keeps the PSA Crypto library initialized for its lifetime
+///
+/// Returned by [`init_scoped`]. When the guard is dropped, [`deinit`] is called, releasing the
+/// underlying implementation's resources. This is primarily useful for test and embedded code
+/// that needs deterministic teardown between runs.
+///
+/// Guards do not reference-count: holding more than one at a time, or mixing one with direct
+/// [`init`]/[`deinit`] calls, means the first guard dropped deinitializes the library out from
+/// under everything else still using it. See the single-owner warning on [`deinit`].
+#[derive(Debug)]
+pub struct CryptoGuard {
+    _private: (),
+}
+
+impl Drop for CryptoGuard {
+    fn drop(&mut self) {
+        deinit();
+    }
+}
+
+/// Initialize the PSA Crypto library and return a guard that deinitializes it on `Drop`
+///
+/// See [`init`] and [`deinit`] for the semantics of initialization and teardown.
+pub fn init_scoped() -> Result<CryptoGuard> {
+    init()?;
+    Ok(CryptoGuard { _private: () })
+}
+
+/// Serializes tests across the crate that exercise the global initialized state
+///
+/// `INITIALISED` is process-wide, so tests that depend on it being in a particular state (via
+/// [`reset_initialized_for_test`]) need to run one at a time rather than racing with every other
+/// test that might call [`init`] or [`deinit`].
+#[cfg(test)]
+pub(crate) static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Force the initialized state back to "uninitialized" for a test
+///
+/// Unlike [`deinit`], this does not call into the underlying implementation - it only resets the
+/// flag so that a test can deterministically observe the uninitialized precondition regardless
+/// of what earlier tests did.
+#[cfg(test)]
+pub(crate) fn reset_initialized_for_test() {
+    INITIALISED.store(false, Ordering::Release);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ensure_initialized_fails_before_init() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        reset_initialized_for_test();
+        assert_eq!(ensure_initialized(), Err(Error::BadState));
+    }
+}
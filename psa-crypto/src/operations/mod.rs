@@ -0,0 +1,49 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # PSA Operations
+//!
+//! This module hosts the cryptographic operations exposed by the PSA Crypto API. It provides
+//! random byte generation ([`generate_random`]); key management, hashing, signing and AEAD are
+//! not wrapped here.
+//!
+//! The PSA spec states that calling any operation before [`crate::init`] has succeeded is
+//! undefined behaviour, which can have security implications (for example, an unseeded RNG).
+//! Every public function added under this module must therefore start with:
+//!
+//! ```ignore
+//! crate::ensure_initialized()?;
+//! ```
+//!
+//! so that the call fails with [`crate::types::status::Error::BadState`] instead of reaching
+//! the underlying implementation, unless strict checking has been turned off with
+//! [`crate::set_strict_init`].
+
+use crate::types::status::{Result, Status};
+
+/// Generate unpredictable random bytes from the PSA implementation's RNG
+///
+/// Fills `output` entirely with cryptographically secure random data.
+pub fn generate_random(output: &mut [u8]) -> Result<()> {
+    crate::ensure_initialized()?;
+
+    Status::from(unsafe {
+        psa_crypto_sys::psa_generate_random(output.as_mut_ptr(), output.len())
+    })
+    .to_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::status::Error;
+
+    #[test]
+    fn generate_random_fails_before_init() {
+        let _guard = crate::TEST_LOCK.lock().unwrap();
+        crate::reset_initialized_for_test();
+
+        let mut output = [0u8; 16];
+        assert_eq!(generate_random(&mut output), Err(Error::BadState));
+    }
+}
@@ -6,12 +6,14 @@
 //! This module defines success and error codes returned by any PSA function.
 
 use log::error;
+#[cfg(feature = "std")]
+use std::fmt;
 
 /// Result type returned by any PSA operation
 pub type Result<T> = core::result::Result<T, Error>;
 
 /// Definition of a PSA status code
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Status {
     /// Status code for success
     Success,
@@ -30,7 +32,7 @@ impl Status {
 }
 
 /// Definition of a PSA status code
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Error {
     /// An error occurred that does not correspond to any defined failure cause
     GenericError,
@@ -74,6 +76,11 @@ pub enum Error {
     InsufficientData,
     /// The key handle is not valid
     InvalidHandle,
+    /// A status code that is not recognised by this crate
+    ///
+    /// The raw `psa_status_t` is preserved so that callers can still inspect and act on
+    /// implementation-specific codes that this crate does not yet have a variant for.
+    Unknown(psa_crypto_sys::psa_status_t),
 }
 
 impl From<Error> for Status {
@@ -98,15 +105,18 @@ impl From<psa_crypto_sys::psa_status_t> for Status {
             psa_crypto_sys::PSA_ERROR_INSUFFICIENT_STORAGE => Error::InsufficientStorage.into(),
             psa_crypto_sys::PSA_ERROR_COMMUNICATION_FAILURE => Error::CommunicationFailure.into(),
             psa_crypto_sys::PSA_ERROR_STORAGE_FAILURE => Error::StorageFailure.into(),
+            psa_crypto_sys::PSA_ERROR_DATA_CORRUPT => Error::DataCorrupt.into(),
+            psa_crypto_sys::PSA_ERROR_DATA_INVALID => Error::DataInvalid.into(),
             psa_crypto_sys::PSA_ERROR_HARDWARE_FAILURE => Error::HardwareFailure.into(),
+            psa_crypto_sys::PSA_ERROR_CORRUPTION_DETECTED => Error::CorruptionDetected.into(),
             psa_crypto_sys::PSA_ERROR_INSUFFICIENT_ENTROPY => Error::InsufficientEntropy.into(),
             psa_crypto_sys::PSA_ERROR_INVALID_SIGNATURE => Error::InvalidSignature.into(),
             psa_crypto_sys::PSA_ERROR_INVALID_PADDING => Error::InvalidPadding.into(),
             psa_crypto_sys::PSA_ERROR_INSUFFICIENT_DATA => Error::InsufficientData.into(),
             psa_crypto_sys::PSA_ERROR_INVALID_HANDLE => Error::InvalidHandle.into(),
             s => {
-                error!("{} not recognised as a valid PSA status.", s);
-                Status::Error(Error::GenericError)
+                error!("{} not recognised as a valid PSA status, preserving raw code.", s);
+                Status::Error(Error::Unknown(s))
             }
         }
     }
@@ -129,19 +139,16 @@ impl From<Status> for psa_crypto_sys::psa_status_t {
                 Error::InsufficientStorage => psa_crypto_sys::PSA_ERROR_INSUFFICIENT_STORAGE,
                 Error::CommunicationFailure => psa_crypto_sys::PSA_ERROR_COMMUNICATION_FAILURE,
                 Error::StorageFailure => psa_crypto_sys::PSA_ERROR_STORAGE_FAILURE,
-                //Error::DataCorrupt => psa_crypto_sys::PSA_ERROR_DATA_CORRUPT,
-                //Error::DataInvalid => psa_crypto_sys::PSA_ERROR_DATA_INVALID,
+                Error::DataCorrupt => psa_crypto_sys::PSA_ERROR_DATA_CORRUPT,
+                Error::DataInvalid => psa_crypto_sys::PSA_ERROR_DATA_INVALID,
                 Error::HardwareFailure => psa_crypto_sys::PSA_ERROR_HARDWARE_FAILURE,
-                //Error::CorruptionDetected => psa_crypto_sys::PSA_ERROR_CORRUPTION_DETECTED,
+                Error::CorruptionDetected => psa_crypto_sys::PSA_ERROR_CORRUPTION_DETECTED,
                 Error::InsufficientEntropy => psa_crypto_sys::PSA_ERROR_INSUFFICIENT_ENTROPY,
                 Error::InvalidSignature => psa_crypto_sys::PSA_ERROR_INVALID_SIGNATURE,
                 Error::InvalidPadding => psa_crypto_sys::PSA_ERROR_INVALID_PADDING,
                 Error::InsufficientData => psa_crypto_sys::PSA_ERROR_INSUFFICIENT_DATA,
                 Error::InvalidHandle => psa_crypto_sys::PSA_ERROR_INVALID_HANDLE,
-                e => {
-                    error!("No equivalent of {:?} to a psa_status_t.", e);
-                    psa_crypto_sys::PSA_ERROR_GENERIC_ERROR
-                }
+                Error::Unknown(code) => code,
             },
         }
     }
@@ -152,3 +159,109 @@ impl From<Status> for Result<()> {
         status.to_result()
     }
 }
+
+#[cfg(feature = "std")]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Error::GenericError => {
+                "An error occurred that does not correspond to any defined failure cause"
+            }
+            Error::NotSupported => {
+                "The requested operation or a parameter is not supported by this implementation"
+            }
+            Error::NotPermitted => "The requested action is denied by a policy",
+            Error::BufferTooSmall => "An output buffer is too small",
+            Error::AlreadyExists => "Asking for an item that already exists",
+            Error::DoesNotExist => "Asking for an item that doesn't exist",
+            Error::BadState => "The requested action cannot be performed in the current state",
+            Error::InvalidArgument => "The parameters passed to the function are invalid",
+            Error::InsufficientMemory => "There is not enough runtime memory",
+            Error::InsufficientStorage => "There is not enough persistent storage",
+            Error::CommunicationFailure => {
+                "There was a communication failure inside the implementation"
+            }
+            Error::StorageFailure => "There was a storage failure that may have led to data loss",
+            Error::DataCorrupt => "Stored data has been corrupted",
+            Error::DataInvalid => "Data read from storage is not valid for the implementation",
+            Error::HardwareFailure => "A hardware failure was detected",
+            Error::CorruptionDetected => "A tampering attempt was detected",
+            Error::InsufficientEntropy => {
+                "There is not enough entropy to generate random data needed for the requested action"
+            }
+            Error::InvalidSignature => "The signature, MAC or hash is incorrect",
+            Error::InvalidPadding => "The decrypted padding is incorrect",
+            Error::InsufficientData => "Insufficient data when attempting to read from a resource",
+            Error::InvalidHandle => "The key handle is not valid",
+            Error::Unknown(code) => {
+                return write!(f, "Unrecognised PSA status code {}", code);
+            }
+        };
+        write!(f, "{}", message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Success => write!(f, "Status code for success"),
+            Status::Error(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Status {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_ERRORS: &[Error] = &[
+        Error::GenericError,
+        Error::NotSupported,
+        Error::NotPermitted,
+        Error::BufferTooSmall,
+        Error::AlreadyExists,
+        Error::DoesNotExist,
+        Error::BadState,
+        Error::InvalidArgument,
+        Error::InsufficientMemory,
+        Error::InsufficientStorage,
+        Error::CommunicationFailure,
+        Error::StorageFailure,
+        Error::DataCorrupt,
+        Error::DataInvalid,
+        Error::HardwareFailure,
+        Error::CorruptionDetected,
+        Error::InsufficientEntropy,
+        Error::InvalidSignature,
+        Error::InvalidPadding,
+        Error::InsufficientData,
+        Error::InvalidHandle,
+    ];
+
+    #[test]
+    fn error_round_trips_through_psa_status_t() {
+        for error in ALL_ERRORS {
+            let status: psa_crypto_sys::psa_status_t = Status::from(*error).into();
+            let round_tripped = Status::from(status);
+            assert_eq!(round_tripped, Status::Error(*error));
+        }
+    }
+
+    #[test]
+    fn unknown_status_code_is_preserved() {
+        // A code far outside the PSA-defined error range (roughly -132 to -153), so it cannot
+        // collide with any of the mapped `PSA_ERROR_*` constants above.
+        let raw = -1_000;
+        let status = Status::from(raw);
+        assert_eq!(status, Status::Error(Error::Unknown(raw)));
+        let back: psa_crypto_sys::psa_status_t = status.into();
+        assert_eq!(back, raw);
+    }
+}